@@ -5,62 +5,166 @@ use rand::rngs::StdRng;
 use rand::{Rng, SeedableRng};
 use rdxsort::{self, RdxSort};
 
-use radix::{RadixSortInt, RadixSortFloat};
+use radix::RadixSort;
 
 const NUM_SAMPLES: [usize; 5] = [1024, 2048, 10000, 1000000, 10000000];
 
+// `target_range` values for the compressed-dynamic-range pattern, swept from a handful of
+// distinct values up to a range wide enough to spread across most of the 256 buckets.
+const COMPRESSED_TARGET_RANGES: [usize; 4] = [4, 64, 4096, 65536];
+
 macro_rules! benchmark_type {
     ($fn_bench:ident, $t:ty, $min:expr, $max:expr) => {
         fn $fn_bench(c: &mut Criterion) {
-            // Generate test data.
-            let generate_data = |num_samples| {
+            // Uniform random data in `[min, max)`, the baseline distribution every other pattern
+            // below is derived from.
+            let gen_uniform = |num_samples| {
                 let rng: StdRng = StdRng::seed_from_u64(0);
                 let dist: Uniform<$t> = Uniform::new($min, $max);
                 let vals: Vec<$t> = rng.sample_iter(dist).take(num_samples).collect();
                 vals
             };
 
-            let mut group = c.benchmark_group(format!("radix_{}", stringify!($t)));
-            for num_samples in NUM_SAMPLES {
-                let vals = generate_data(num_samples);
-                group.bench_with_input(
-                    BenchmarkId::from_parameter(num_samples),
-                    &vals,
-                    |b, vals| {
-                        let mut vals = vals.clone();
-                        b.iter(|| vals.radix_sort());
-                    },
-                );
-            }
-            group.finish();
-
-            let mut group = c.benchmark_group(format!("rdxsort_{}", stringify!($t)));
-            for num_samples in NUM_SAMPLES {
-                let vals = generate_data(num_samples);
-                group.bench_with_input(
-                    BenchmarkId::from_parameter(num_samples),
-                    &vals,
-                    |b, vals| {
-                        let mut vals = vals.clone();
-                        b.iter(|| vals.rdxsort());
-                    },
-                );
+            // Already sorted ascending.
+            let gen_sorted = |num_samples| {
+                let mut vals = gen_uniform(num_samples);
+                vals.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                vals
+            };
+
+            // Sorted descending, the worst case for algorithms that assume runs of ascending data.
+            let gen_reverse_sorted = |num_samples| {
+                let mut vals = gen_sorted(num_samples);
+                vals.reverse();
+                vals
+            };
+
+            // Sorted with ~1% of elements swapped to random positions.
+            let gen_nearly_sorted = |num_samples| {
+                let mut vals = gen_sorted(num_samples);
+                let mut rng: StdRng = StdRng::seed_from_u64(1);
+                let num_swaps = (num_samples / 100).max(1);
+                let swap_dist = Uniform::new(0, num_samples);
+                for _ in 0..num_swaps {
+                    let i = rng.sample(swap_dist);
+                    let j = rng.sample(swap_dist);
+                    vals.swap(i, j);
+                }
+                vals
+            };
+
+            // Drawn from a tiny set of distinct values, so most of the 256 buckets stay empty on
+            // every pass.
+            let gen_few_unique = |num_samples| {
+                const NUM_UNIQUE: usize = 8;
+                // Widen to f64 before subtracting: `$max - $min` in the element type itself
+                // overflows for the full-range signed instantiations (e.g. `i32::MAX - i32::MIN`).
+                let span = ($max as f64) - ($min as f64);
+                let step = span / (NUM_UNIQUE as f64);
+                let uniques: Vec<$t> = (0..NUM_UNIQUE)
+                    .map(|i| (($min as f64) + step * i as f64) as $t)
+                    .collect();
+                let mut rng: StdRng = StdRng::seed_from_u64(2);
+                let pick_dist = Uniform::new(0, NUM_UNIQUE);
+                (0..num_samples)
+                    .map(|_| uniques[rng.sample(pick_dist)])
+                    .collect::<Vec<$t>>()
+            };
+
+            // Rescales a uniform sample's observed `[min, max]` into `[0, target_range)`, shrinking
+            // the dynamic range down to `target_range` distinct values.
+            let gen_compressed = |num_samples, target_range: usize| {
+                let vals = gen_uniform(num_samples);
+                let lo = vals.iter().cloned().fold($max, |a, b| if b < a { b } else { a });
+                let hi = vals.iter().cloned().fold($min, |a, b| if b > a { b } else { a });
+                // Do the whole rescale in f64: `hi - lo` and `v - lo` in the element type itself
+                // overflow for the full-range signed instantiations (e.g. `i32::MAX - i32::MIN`).
+                let lo_f = lo as f64;
+                let span = (hi as f64) - lo_f;
+                let mult = if span > 0.0 {
+                    (target_range as f64 - 1.0) / span
+                } else {
+                    0.0
+                };
+                vals.iter()
+                    .map(|&v| (((v as f64 - lo_f) * mult).round() as $t))
+                    .collect::<Vec<$t>>()
+            };
+
+            let patterns: Vec<(&str, Box<dyn Fn(usize) -> Vec<$t>>)> = vec![
+                ("uniform", Box::new(gen_uniform)),
+                ("sorted", Box::new(gen_sorted)),
+                ("reverse_sorted", Box::new(gen_reverse_sorted)),
+                ("nearly_sorted", Box::new(gen_nearly_sorted)),
+                ("few_unique", Box::new(gen_few_unique)),
+            ];
+
+            for (pattern_name, generate_data) in &patterns {
+                let mut group =
+                    c.benchmark_group(format!("radix_{}_{}", stringify!($t), pattern_name));
+                for num_samples in NUM_SAMPLES {
+                    let vals = generate_data(num_samples);
+                    group.bench_with_input(
+                        BenchmarkId::from_parameter(num_samples),
+                        &vals,
+                        |b, vals| {
+                            let mut vals = vals.clone();
+                            b.iter(|| vals.radix_sort());
+                        },
+                    );
+                }
+                group.finish();
+
+                let mut group =
+                    c.benchmark_group(format!("rdxsort_{}_{}", stringify!($t), pattern_name));
+                for num_samples in NUM_SAMPLES {
+                    let vals = generate_data(num_samples);
+                    group.bench_with_input(
+                        BenchmarkId::from_parameter(num_samples),
+                        &vals,
+                        |b, vals| {
+                            let mut vals = vals.clone();
+                            b.iter(|| vals.rdxsort());
+                        },
+                    );
+                }
+                group.finish();
+
+                let mut group = c
+                    .benchmark_group(format!("std_quicksort_{}_{}", stringify!($t), pattern_name));
+                for num_samples in NUM_SAMPLES {
+                    let vals = generate_data(num_samples);
+                    group.bench_with_input(
+                        BenchmarkId::from_parameter(num_samples),
+                        &vals,
+                        |b, vals| {
+                            let mut vals = vals.clone();
+                            b.iter(|| vals.sort_by(|a, b| a.partial_cmp(b).unwrap()));
+                        },
+                    );
+                }
+                group.finish();
             }
-            group.finish();
-
-            let mut group = c.benchmark_group(format!("std_quicksort_{}", stringify!($t)));
-            for num_samples in NUM_SAMPLES {
-                let vals = generate_data(num_samples);
-                group.bench_with_input(
-                    BenchmarkId::from_parameter(num_samples),
-                    &vals,
-                    |b, vals| {
-                        let mut vals = vals.clone();
-                        b.iter(|| vals.sort_by(|a, b| a.partial_cmp(b).unwrap()));
-                    },
-                );
+
+            for target_range in COMPRESSED_TARGET_RANGES {
+                let mut group = c.benchmark_group(format!(
+                    "radix_{}_compressed_{}",
+                    stringify!($t),
+                    target_range
+                ));
+                for num_samples in NUM_SAMPLES {
+                    let vals = gen_compressed(num_samples, target_range);
+                    group.bench_with_input(
+                        BenchmarkId::from_parameter(num_samples),
+                        &vals,
+                        |b, vals| {
+                            let mut vals = vals.clone();
+                            b.iter(|| vals.radix_sort());
+                        },
+                    );
+                }
+                group.finish();
             }
-            group.finish();
         }
     };
 }
@@ -82,4 +186,3 @@ criterion_group!(
     bench_f64,
 );
 criterion_main!(benches);
-
@@ -0,0 +1,5 @@
+pub mod radix;
+
+pub use radix::{
+    radix_argsort, radix_sort, ArgSortBuffer, Radix, RadixKey, RadixSort, RadixSortByKey,
+};
@@ -1,4 +1,4 @@
-use radix::RadixSortFloat;
+use radix::RadixSort;
 
 // Just a dumb script that I used for debugging.
 fn main() {
@@ -0,0 +1,214 @@
+use std::{fmt::Debug, mem::size_of};
+
+use crate::radix::radix::{write_at, Radix, RadixKey, NUM_BUCKETS, SHIFT_BITS};
+
+/**
+ * ====================================================================================================
+ * Argsort: instead of reordering `T` itself, sort `(key, original_index)` pairs and return just the
+ * indices. This is the building block for both `radix_argsort` (return the permutation directly) and
+ * `radix_sort_by_key` (apply the permutation to reorder an arbitrary `Vec<T>` by an extracted key),
+ * and it's how you sort by a field without requiring the whole record to be `Copy`.
+ * ====================================================================================================
+ */
+pub struct ArgSortBuffer<K> {
+    pairs: Vec<(K, usize)>,
+    pairs_buf: Vec<(K, usize)>,
+}
+
+impl<K> ArgSortBuffer<K>
+where
+    K: Radix + Clone + Copy + Debug,
+{
+    pub fn new() -> Self {
+        ArgSortBuffer {
+            pairs: Vec::new(),
+            pairs_buf: Vec::new(),
+        }
+    }
+
+    /// Returns the permutation of `0..keys.len()` that would sort `keys`. Reuses its internal
+    /// `pairs`/`pairs_buf` scratch vectors across calls, so repeated argsorts of same-length `keys`
+    /// (e.g. resorting after each streaming batch) don't reallocate them.
+    pub fn argsort(&mut self, keys: &[K]) -> Vec<usize> {
+        self.pairs.clear();
+        self.pairs
+            .extend(keys.iter().enumerate().map(|(i, k)| (*k, i)));
+        self.pairs_buf.clear();
+        self.pairs_buf.extend_from_slice(&self.pairs);
+
+        let num_iters = size_of::<K>();
+        let mut counts: [usize; NUM_BUCKETS] = [0; NUM_BUCKETS];
+        let mut offsets: [usize; NUM_BUCKETS] = [0; NUM_BUCKETS];
+
+        for n_iter in 0..num_iters {
+            let shift_bits = (n_iter * SHIFT_BITS) as u8;
+
+            counts.iter_mut().for_each(|c| *c = 0);
+            self.pairs
+                .iter()
+                .map(|(k, _)| k.to_radix(shift_bits))
+                .for_each(|b| counts[b] += 1);
+
+            offsets[0] = 0;
+            (1..NUM_BUCKETS).for_each(|i| offsets[i] = offsets[i - 1] + counts[i - 1]);
+
+            for pair in &self.pairs_buf {
+                let b = pair.0.to_radix(shift_bits);
+                write_at(&mut self.pairs, offsets[b], *pair);
+                offsets[b] += 1;
+            }
+            self.pairs_buf.copy_from_slice(&self.pairs);
+        }
+
+        self.pairs.iter().map(|(_, i)| *i).collect()
+    }
+
+    /// Returns the permutation of `0..vals.len()` that sorts `vals`, reusing this buffer's scratch
+    /// vectors across calls. The reusable counterpart to the one-shot `radix_argsort` free function.
+    pub fn argsort_by<T>(&mut self, vals: &[T]) -> Vec<usize>
+    where
+        T: RadixKey<Key = K>,
+    {
+        let keys: Vec<K> = vals.iter().map(|v| v.to_key()).collect();
+        self.argsort(&keys)
+    }
+
+    /// Sorts `vals` in place by the key extracted via `key`, reusing this buffer's scratch vectors
+    /// across calls. The reusable counterpart to the one-shot `RadixSortByKey::radix_sort_by_key`.
+    pub fn sort_by_key<T, KR, F>(&mut self, vals: &mut [T], key: F)
+    where
+        KR: RadixKey<Key = K>,
+        F: Fn(&T) -> KR,
+    {
+        let keys: Vec<K> = vals.iter().map(|v| key(v).to_key()).collect();
+        let perm = self.argsort(&keys);
+        apply_permutation(vals, perm);
+    }
+}
+
+impl<K> Default for ArgSortBuffer<K>
+where
+    K: Radix + Clone + Copy + Debug,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns `perm`'s inverse: `inverse[perm[i]] == i` for every `i`.
+fn invert_permutation(perm: &[usize]) -> Vec<usize> {
+    let mut inverse = vec![0usize; perm.len()];
+    for (i, &p) in perm.iter().enumerate() {
+        inverse[p] = i;
+    }
+    inverse
+}
+
+/// Applies the permutation `perm` to `vals` in place: after this call, `vals[i]` holds what was
+/// `vals[perm[i]]` beforehand. Walks cycles of `perm`'s inverse; each swap puts one element into its
+/// final resting place, so a cycle of length `k` takes `k - 1` swaps.
+fn apply_permutation<T>(vals: &mut [T], perm: Vec<usize>) {
+    let mut inverse = invert_permutation(&perm);
+    for i in 0..inverse.len() {
+        while inverse[i] != i {
+            let j = inverse[i];
+            vals.swap(i, j);
+            inverse.swap(i, j);
+        }
+    }
+}
+
+/**
+ * Returns the permutation of `0..vals.len()` that sorts `vals`, without moving `vals` itself. This
+ * is a one-shot convenience that allocates a fresh `ArgSortBuffer` per call; callers doing repeated
+ * argsorts (e.g. resorting same-length data after each streaming batch) should hold their own
+ * `ArgSortBuffer` and call `ArgSortBuffer::argsort_by` directly to reuse its scratch vectors.
+ */
+pub fn radix_argsort<T>(vals: &[T]) -> Vec<usize>
+where
+    T: RadixKey,
+{
+    ArgSortBuffer::<T::Key>::new().argsort_by(vals)
+}
+
+/**
+ * Sorts `vals` in place by the `RadixKey` extracted from each element via `key`, for the common
+ * "sort records by one field" case that `RadixSort` (which requires `T: RadixKey` itself) can't
+ * serve. This is a one-shot convenience that allocates a fresh `ArgSortBuffer` per call; callers
+ * doing repeated sorts should hold their own `ArgSortBuffer` and call `ArgSortBuffer::sort_by_key`
+ * directly to reuse its scratch vectors.
+ */
+pub trait RadixSortByKey<T> {
+    fn radix_sort_by_key<K, F>(&mut self, key: F)
+    where
+        K: RadixKey,
+        F: Fn(&T) -> K;
+}
+
+impl<T> RadixSortByKey<T> for Vec<T> {
+    fn radix_sort_by_key<K, F>(&mut self, key: F)
+    where
+        K: RadixKey,
+        F: Fn(&T) -> K,
+    {
+        ArgSortBuffer::<K::Key>::new().sort_by_key(self, key);
+    }
+}
+
+/**
+ * ====================================================================================================
+ * Tests.
+ * ====================================================================================================
+ */
+#[cfg(test)]
+mod tests {
+    use super::ArgSortBuffer;
+    use crate::{radix_argsort, RadixSortByKey};
+
+    #[test]
+    fn test_radix_argsort_returns_sorting_permutation() {
+        let vals = vec![30, 10, 20, 10, 0];
+        let perm = radix_argsort(&vals);
+        let sorted: Vec<i32> = perm.iter().map(|&i| vals[i]).collect();
+        assert_eq!(sorted, vec![0, 10, 10, 20, 30]);
+    }
+
+    #[test]
+    fn test_radix_argsort_stable_on_ties() {
+        // Ties should keep their original relative order, so the permutation recovers which `10`
+        // came first.
+        let vals = [(10, "a"), (5, "b"), (10, "c")];
+        let perm = radix_argsort(&vals.iter().map(|(k, _)| *k).collect::<Vec<i32>>());
+        let ordered: Vec<&str> = perm.iter().map(|&i| vals[i].1).collect();
+        assert_eq!(ordered, vec!["b", "a", "c"]);
+    }
+
+    #[test]
+    fn test_radix_sort_by_key() {
+        let mut records = vec![("c", 3), ("a", 1), ("b", 2)];
+        records.radix_sort_by_key(|&(_, n)| n);
+        assert_eq!(records, vec![("a", 1), ("b", 2), ("c", 3)]);
+    }
+
+    #[test]
+    fn test_arg_sort_buffer_reuse_across_calls() {
+        // The whole point of `ArgSortBuffer` is that repeated argsorts on same-length data reuse
+        // its scratch vectors instead of reallocating, so check it still gives the right answer
+        // on a second call with fresh keys rather than just the first.
+        // `ArgSortBuffer`'s own type parameter is the `Radix`-backed unsigned key (`i32::Key`),
+        // not `i32` itself.
+        let mut buf: ArgSortBuffer<u32> = ArgSortBuffer::new();
+
+        let first = vec![3, 1, 2];
+        let perm = buf.argsort_by(&first);
+        assert_eq!(perm, vec![1, 2, 0]);
+
+        let second = vec![30, 10, 20];
+        let perm = buf.argsort_by(&second);
+        assert_eq!(perm, vec![1, 2, 0]);
+
+        let mut records = vec![("z", 9), ("y", 1), ("x", 5)];
+        buf.sort_by_key(&mut records, |&(_, n)| n);
+        assert_eq!(records, vec![("y", 1), ("x", 5), ("z", 9)]);
+    }
+}
@@ -0,0 +1,7 @@
+pub mod radix;
+pub mod msb;
+pub mod swc;
+pub mod argsort;
+
+pub use argsort::{radix_argsort, ArgSortBuffer, RadixSortByKey};
+pub use radix::{radix_sort, Radix, RadixKey, RadixSort};
@@ -0,0 +1,176 @@
+use std::{fmt::Debug, mem::size_of};
+
+use crate::radix::radix::{write_at, Radix, RadixKey, NUM_BUCKETS, SHIFT_BITS};
+
+/**
+ * ====================================================================================================
+ * MSB (most-significant-byte) recursive radix sort. Unlike the LSB variant in `radix.rs`, which always
+ * performs `size_of::<T>()` passes over the full input, this partitions on the top byte first and then
+ * recurses into each of the 256 resulting buckets on the next byte down. A bucket at or below
+ * `INSERTION_SORT_THRESHOLD` is handed off to an insertion sort instead of being recursed into, since
+ * insertion sort's low constant factor wins once a partition is that small. This makes MSB radix sort
+ * a good fit for partially-sorted or skewed inputs, where most buckets collapse after one or two passes
+ * and LSB's fixed number of full passes is wasted work.
+ *
+ * Like the LSB sort, this operates on `RadixKey::Key` rather than `T` directly, so there's no separate
+ * sign-bit handling here: the key's own unsigned bitwise order already matches `T`'s real order.
+ * ====================================================================================================
+ */
+pub(crate) const INSERTION_SORT_THRESHOLD: usize = 32;
+
+pub(crate) fn insertion_sort<T>(vals: &mut [T])
+where
+    T: PartialOrd + Copy,
+{
+    for i in 1..vals.len() {
+        let mut j = i;
+        while j > 0 && vals[j - 1] > vals[j] {
+            vals.swap(j - 1, j);
+            j -= 1;
+        }
+    }
+}
+
+/**
+ * Buckets `vals` (using `buf` as scratch space of the same length) on the byte selected by
+ * `shift_bits`, then recurses into each bucket on the next byte down. `shift_bits == 0` is the last
+ * byte, so the recursion stops after bucketing on it.
+ */
+fn msb_recurse<K>(vals: &mut [K], buf: &mut [K], shift_bits: u8)
+where
+    K: Radix + Clone + Copy + Debug + PartialOrd,
+{
+    if vals.len() <= INSERTION_SORT_THRESHOLD {
+        insertion_sort(vals);
+        return;
+    }
+
+    let mut counts: [usize; NUM_BUCKETS] = [0; NUM_BUCKETS];
+    vals.iter()
+        .map(|n| n.to_radix(shift_bits))
+        .for_each(|b| counts[b] += 1);
+
+    let mut offsets: [usize; NUM_BUCKETS] = [0; NUM_BUCKETS];
+    offsets[0] = 0;
+    (1..NUM_BUCKETS).for_each(|i| offsets[i] = offsets[i - 1] + counts[i - 1]);
+    let bucket_starts = offsets;
+
+    buf.copy_from_slice(vals);
+    for n in buf.iter() {
+        let b = n.to_radix(shift_bits);
+        write_at(vals, offsets[b], *n);
+        offsets[b] += 1;
+    }
+
+    if shift_bits == 0 {
+        return;
+    }
+
+    for b in 0..NUM_BUCKETS {
+        let (start, end) = (bucket_starts[b], offsets[b]);
+        if end > start {
+            msb_recurse(
+                &mut vals[start..end],
+                &mut buf[start..end],
+                shift_bits - SHIFT_BITS as u8,
+            );
+        }
+    }
+}
+
+fn msb_sort_keys<K>(keys: &mut Vec<K>)
+where
+    K: Radix + Clone + Copy + Debug + PartialOrd,
+{
+    let num_iters = size_of::<K>();
+    if num_iters == 0 {
+        return;
+    }
+    let top_shift = ((num_iters - 1) * SHIFT_BITS) as u8;
+    let mut buf: Vec<K> = keys.clone();
+    msb_recurse(keys, &mut buf, top_shift);
+}
+
+/**
+ * Sorts `vals` in place using MSB radix sort: maps every element through `RadixKey` into its unsigned
+ * key, sorts the keys, then maps them back.
+ */
+pub fn radix_sort_msb<T>(vals: &mut Vec<T>)
+where
+    T: RadixKey,
+    T::Key: PartialOrd,
+{
+    let mut keys: Vec<T::Key> = vals.iter().map(|v| v.to_key()).collect();
+    msb_sort_keys(&mut keys);
+    for (v, k) in vals.iter_mut().zip(keys) {
+        *v = T::from_key(k);
+    }
+}
+
+/**
+ * ====================================================================================================
+ * Tests. `radix_sort` (LSB) is used as the oracle, since it's already covered end-to-end in
+ * `radix.rs`'s own tests; these check that MSB's recursive short-circuiting produces the same
+ * result, across lengths that straddle `INSERTION_SORT_THRESHOLD` from both sides plus a couple of
+ * degenerate shapes (empty, singleton, all-equal).
+ * ====================================================================================================
+ */
+#[cfg(test)]
+mod tests {
+    use super::INSERTION_SORT_THRESHOLD;
+    use crate::RadixSort;
+
+    const TEST_LENS: [usize; 9] = [0, 1, 31, 32, 33, 63, 256, 1000, 5000];
+
+    #[test]
+    fn test_msb_matches_lsb_i32() {
+        for &n in &TEST_LENS {
+            let mut vals: Vec<i32> = (0..n as i32).rev().map(|i| i - (n as i32) / 2).collect();
+            let mut expected = vals.clone();
+            expected.radix_sort();
+            vals.radix_sort_msb();
+            assert_eq!(vals, expected, "n = {}", n);
+        }
+    }
+
+    #[test]
+    fn test_msb_matches_lsb_f64_with_negatives() {
+        for &n in &TEST_LENS {
+            let mut vals: Vec<f64> = (0..n as i64)
+                .rev()
+                .map(|i| (i - (n as i64) / 2) as f64 * 0.5)
+                .collect();
+            let mut expected = vals.clone();
+            expected.radix_sort();
+            vals.radix_sort_msb();
+            assert_eq!(vals, expected, "n = {}", n);
+        }
+    }
+
+    #[test]
+    fn test_msb_all_equal_buckets() {
+        for &n in &TEST_LENS {
+            let mut vals: Vec<i32> = vec![42; n];
+            let expected = vals.clone();
+            vals.radix_sort_msb();
+            assert_eq!(vals, expected, "n = {}", n);
+        }
+    }
+
+    #[test]
+    fn test_msb_insertion_sort_threshold_boundary() {
+        // One below, exactly at, and one above `INSERTION_SORT_THRESHOLD`, so both the
+        // insertion-sort base case and the first level of recursion are exercised.
+        for &n in &[
+            INSERTION_SORT_THRESHOLD - 1,
+            INSERTION_SORT_THRESHOLD,
+            INSERTION_SORT_THRESHOLD + 1,
+        ] {
+            let mut vals: Vec<i32> = (0..n as i32).rev().collect();
+            let mut expected = vals.clone();
+            expected.radix_sort();
+            vals.radix_sort_msb();
+            assert_eq!(vals, expected, "n = {}", n);
+        }
+    }
+}
@@ -1,8 +1,5 @@
-use num_traits::{Float, PrimInt};
-use std::{
-    fmt::Debug,
-    mem::{size_of, transmute},
-};
+use num_traits::Zero;
+use std::{fmt::Debug, mem::size_of};
 
 /**
  * ====================================================================================================
@@ -10,11 +7,9 @@ use std::{
  * typical in radix sort implementations from what I can tell.
  * ====================================================================================================
  */
-const MASK: u8 = 0xff;
-const SHIFT_BITS: usize = 8;
-const NUM_BUCKETS: usize = 256;
-// Negative values are contained in the latter 128 bins. Recall that 127 = 0x7f = 0111 1111.
-const FIRST_NEG_BUCKET: usize = NUM_BUCKETS / 2;
+pub(crate) const MASK: u8 = 0xff;
+pub(crate) const SHIFT_BITS: usize = 8;
+pub(crate) const NUM_BUCKETS: usize = 256;
 
 /**
  * ====================================================================================================
@@ -41,80 +36,247 @@ macro_rules! radix_for_type {
     };
 }
 
-#[macro_export]
-macro_rules! radix_for_type_with_transmute {
-    ($type_from:ty, $type_into:ty) => {
-        impl Radix for $type_from {
+// The core radix sort only ever bins these unsigned types; every other supported type is mapped
+// onto one of them via `RadixKey` below.
+radix_for_type!(u8);
+radix_for_type!(u16);
+radix_for_type!(u32);
+radix_for_type!(u64);
+radix_for_type!(u128);
+
+/**
+ * ====================================================================================================
+ * `RadixKey` maps a type onto an unsigned integer whose *unsigned bitwise order* matches the type's
+ * own total order. This means the core sort never has to special-case a final byte for sign bits: it
+ * just runs a plain unsigned LSB pass over `Key` and the per-type quirks live entirely in `to_key` /
+ * `from_key`. Signed integers flip only the sign bit, which slides the 128 negative buckets below the
+ * positives. Floats use the classic trick: flip the sign bit for positives, and flip *every* bit for
+ * negatives, which also makes -0.0/+0.0 and subnormals land in the right place. `from_key` is each
+ * transform's own inverse, applied once the keys come back out sorted.
+ * ====================================================================================================
+ */
+pub trait RadixKey: Copy {
+    type Key: Radix + Clone + Copy + Debug;
+    fn to_key(self) -> Self::Key;
+    fn from_key(key: Self::Key) -> Self;
+}
+
+macro_rules! radix_key_for_unsigned {
+    ($type:ty) => {
+        impl RadixKey for $type {
+            type Key = $type;
             #[inline]
-            fn to_radix(&self, offset: u8) -> usize {
-                unsafe { transmute::<$type_from, $type_into>(*self) }.to_radix(offset)
+            fn to_key(self) -> $type {
+                self
+            }
+            #[inline]
+            fn from_key(key: $type) -> $type {
+                key
             }
         }
     };
 }
+radix_key_for_unsigned!(u8);
+radix_key_for_unsigned!(u16);
+radix_key_for_unsigned!(u32);
+radix_key_for_unsigned!(u64);
+radix_key_for_unsigned!(u128);
 
-// Generate trait implementations for the following unsigned types. The core radix sort only
-// operates on these types.
-radix_for_type!(u32);
-radix_for_type!(u64);
-radix_for_type!(u128);
-// Generate trait implementations for the following types that can be cast into the specified
-// unsigned type. 
-radix_for_type_with_transmute!(i32, u32);
-radix_for_type_with_transmute!(i64, u64);
-radix_for_type_with_transmute!(i128, u128);
-radix_for_type_with_transmute!(f32, u32);
-radix_for_type_with_transmute!(f64, u64);
+// `usize` is already unsigned, so unlike `radix_key_for_signed!` there's no sign bit to flip: the
+// `as` cast to its pointer-width-matched backing type already preserves order.
+macro_rules! radix_key_for_usize {
+    ($unsigned:ty) => {
+        impl RadixKey for usize {
+            type Key = $unsigned;
+            #[inline]
+            fn to_key(self) -> $unsigned {
+                self as $unsigned
+            }
+            #[inline]
+            fn from_key(key: $unsigned) -> usize {
+                key as usize
+            }
+        }
+    };
+}
+
+// The `as` cast between same-width signed/unsigned integers is a defined bit-pattern
+// reinterpretation (unlike `transmute`, it can't be misused across mismatched sizes), so it gives
+// the same codegen with no unsafe required.
+macro_rules! radix_key_for_signed {
+    ($type:ty, $unsigned:ty) => {
+        impl RadixKey for $type {
+            type Key = $unsigned;
+            #[inline]
+            fn to_key(self) -> $unsigned {
+                (self as $unsigned) ^ (1 << (<$unsigned>::BITS - 1))
+            }
+            #[inline]
+            fn from_key(key: $unsigned) -> $type {
+                (key ^ (1 << (<$unsigned>::BITS - 1))) as $type
+            }
+        }
+    };
+}
+radix_key_for_signed!(i8, u8);
+radix_key_for_signed!(i16, u16);
+radix_key_for_signed!(i32, u32);
+radix_key_for_signed!(i64, u64);
+radix_key_for_signed!(i128, u128);
+
+// `usize`/`isize` don't have a fixed width, so their `RadixKey::Key` is picked by pointer width to
+// match the platform's actual representation instead of hardcoding e.g. `u64`.
+#[cfg(target_pointer_width = "16")]
+radix_key_for_usize!(u16);
+#[cfg(target_pointer_width = "32")]
+radix_key_for_usize!(u32);
+#[cfg(target_pointer_width = "64")]
+radix_key_for_usize!(u64);
+
+#[cfg(target_pointer_width = "16")]
+radix_key_for_signed!(isize, u16);
+#[cfg(target_pointer_width = "32")]
+radix_key_for_signed!(isize, u32);
+#[cfg(target_pointer_width = "64")]
+radix_key_for_signed!(isize, u64);
+
+// `to_bits`/`from_bits` reinterpret the float's bits as an unsigned integer of the same width,
+// exactly what `transmute` was doing here, but through an API that can't be handed a mismatched
+// size by mistake.
+macro_rules! radix_key_for_float {
+    ($type:ty, $unsigned:ty) => {
+        impl RadixKey for $type {
+            type Key = $unsigned;
+            #[inline]
+            fn to_key(self) -> $unsigned {
+                let bits = self.to_bits();
+                if bits >> (<$unsigned>::BITS - 1) == 1 {
+                    !bits
+                } else {
+                    bits | (1 << (<$unsigned>::BITS - 1))
+                }
+            }
+            #[inline]
+            fn from_key(key: $unsigned) -> $type {
+                let bits = if key >> (<$unsigned>::BITS - 1) == 1 {
+                    key & !(1 << (<$unsigned>::BITS - 1))
+                } else {
+                    !key
+                };
+                <$type>::from_bits(bits)
+            }
+        }
+    };
+}
+radix_key_for_float!(f32, u32);
+radix_key_for_float!(f64, u64);
+
+impl RadixKey for bool {
+    type Key = u8;
+    #[inline]
+    fn to_key(self) -> u8 {
+        self as u8
+    }
+    #[inline]
+    fn from_key(key: u8) -> bool {
+        key != 0
+    }
+}
+
+impl RadixKey for char {
+    type Key = u32;
+    #[inline]
+    fn to_key(self) -> u32 {
+        self as u32
+    }
+    #[inline]
+    fn from_key(key: u32) -> char {
+        // `from_key` is reachable on any `u32` through the public `RadixKey` trait, not just keys
+        // that round-tripped through `to_key`, so an unchecked conversion would be unsound for
+        // external callers. The sort itself only ever round-trips valid scalar values, so this
+        // never actually panics in practice.
+        char::from_u32(key).expect("radix key is a valid Unicode scalar value")
+    }
+}
 
 /**
  * ====================================================================================================
- * Implementations of radix sort for vectors of types that implement Radix. There are separate
- * versions for integer and floating point types because I'm not sure how to accomplish it with one
- * trait in Rust yet (I'm what's colloquially referred to as a "noob").
- * ==================================================================================================== 
+ * The radix sort entry points. Every `RadixKey` type shares the same LSB core (`radix_sort`) and the
+ * same MSB/SWC variants from the `msb`/`swc` sibling modules, so there's a single trait instead of the
+ * old int/float split.
+ * ====================================================================================================
  */
-pub trait RadixSortInt {
+pub trait RadixSort {
     fn radix_sort(&mut self);
+    fn radix_sort_msb(&mut self);
+    fn radix_sort_swc(&mut self);
+    fn radix_sort_msb_swc(&mut self);
+    /// Returns the permutation that sorts `self`, without moving `self`'s elements.
+    fn radix_argsort(&self) -> Vec<usize>;
 }
-impl<I> RadixSortInt for Vec<I>
+
+impl<T> RadixSort for Vec<T>
 where
-    I: PrimInt + Radix + Debug,
+    T: RadixKey + Debug,
+    T::Key: PartialOrd + Zero,
 {
     fn radix_sort(&mut self) {
-        radix_sort_int(self)
+        radix_sort(self)
     }
-}
 
-pub trait RadixSortFloat {
-    fn radix_sort(&mut self);
-}
-impl<F> RadixSortFloat for Vec<F>
-where
-    F: Float + Radix + Debug,
-{
-    fn radix_sort(&mut self) {
-        radix_sort_float(self)
+    fn radix_sort_msb(&mut self) {
+        crate::radix::msb::radix_sort_msb(self)
+    }
+
+    fn radix_sort_swc(&mut self) {
+        crate::radix::swc::radix_sort_swc(self)
+    }
+
+    fn radix_sort_msb_swc(&mut self) {
+        crate::radix::swc::radix_sort_msb_swc(self)
+    }
+
+    fn radix_argsort(&self) -> Vec<usize> {
+        crate::radix::argsort::radix_argsort(self)
     }
 }
 
 /**
  * ====================================================================================================
- * Core implementations of radix sort. These are all written to use byte sized buckets. This
- * particular implementation is the LSB variant, which allows for straightforward sorting of
- * arbitrarily sized data types. 
+ * Every bucketing pass across `radix.rs`/`msb.rs`/`argsort.rs` scatters elements to `vals[offsets[b]]`
+ * for a `b` already bounded by `NUM_BUCKETS`, so the write itself never needs a bounds check. By
+ * default this is an unchecked write to keep that hot path at its natural cost; building with the
+ * `checked` feature swaps it for a plain indexed write instead, for consumers that need
+ * `#![forbid(unsafe_code)]`.
  * ====================================================================================================
  */
+#[inline]
+pub(crate) fn write_at<T: Copy>(vals: &mut [T], idx: usize, val: T) {
+    #[cfg(feature = "checked")]
+    {
+        vals[idx] = val;
+    }
+    #[cfg(not(feature = "checked"))]
+    unsafe {
+        *vals.get_unchecked_mut(idx) = val;
+    }
+}
+
 /**
- * This implementation is for floating point numbers using byte sizes buckets. It handles negative values.
+ * ====================================================================================================
+ * Core implementation of radix sort. This is the LSB variant, which allows for straightforward sorting
+ * of arbitrarily sized unsigned key types with no per-iteration branching, since `RadixKey::to_key`
+ * has already made the unsigned bitwise order match the real order.
+ * ====================================================================================================
  */
-pub fn radix_sort_float<T>(vals: &mut Vec<T>)
+fn radix_sort_keys<K>(vals: &mut Vec<K>)
 where
-    T: Radix + Clone + Copy + Debug,
+    K: Radix + Clone + Copy + Debug,
 {
-    let num_iters = size_of::<T>();
+    let num_iters = size_of::<K>();
     let mut counts: [usize; NUM_BUCKETS] = [0; NUM_BUCKETS];
     let mut offsets: [usize; NUM_BUCKETS] = [0; NUM_BUCKETS];
-    let mut vals_buf: Vec<T> = vals.clone();
+    let mut vals_buf: Vec<K> = vals.clone();
 
     for n_iter in 0..num_iters {
         let shift_bits = (n_iter * SHIFT_BITS) as u8;
@@ -124,87 +286,30 @@ where
             .map(|n| n.to_radix(shift_bits))
             .for_each(|b| counts[b] += 1);
 
-        // Negative values only need to be handled on the final iteration. This is because the sign
-        // bit is always MSB.
-        if n_iter == num_iters - 1 {
-            offsets[0] = counts[FIRST_NEG_BUCKET..].iter().sum();
-            (1..FIRST_NEG_BUCKET).for_each(|i| offsets[i] = offsets[i - 1] + counts[i - 1]);
-
-            // Reverse order prefix sum to fix the ordering for negative values.
-            offsets[NUM_BUCKETS-1] = 0;
-            (0..FIRST_NEG_BUCKET-1).for_each(|i| offsets[NUM_BUCKETS-2-i] = offsets[NUM_BUCKETS-1-i] + counts[NUM_BUCKETS-1-i]);
-
-            // Fix positioning of negative values.
-            (FIRST_NEG_BUCKET..NUM_BUCKETS).for_each(|i| offsets[i] += counts[i]);
-
-        } else {
-            offsets[0] = 0;
-            (1..NUM_BUCKETS).for_each(|i| offsets[i] = offsets[i - 1] + counts[i - 1]);
-        }
+        offsets[0] = 0;
+        (1..NUM_BUCKETS).for_each(|i| offsets[i] = offsets[i - 1] + counts[i - 1]);
 
         for n in &vals_buf {
             let b = n.to_radix(shift_bits);
-            if (n_iter == num_iters - 1) && (b >= FIRST_NEG_BUCKET) {
-                offsets[b] -= 1;
-                unsafe {
-                    *vals.get_unchecked_mut(*offsets.get_unchecked(b)) = *n;
-                }
-            } else {
-                unsafe {
-                    *vals.get_unchecked_mut(*offsets.get_unchecked(b)) = *n;
-                }
-                offsets[b] += 1;
-            }
+            write_at(vals, offsets[b], *n);
+            offsets[b] += 1;
         }
-        vals_buf.copy_from_slice(&vals);
+        vals_buf.copy_from_slice(vals);
     }
 }
 
 /**
- * This implementation is for radix sorting integer types using byte sized buckets. It handles
- * negative values.
+ * Sorts `vals` in place by mapping every element through `RadixKey` into its unsigned key, running
+ * the plain unsigned LSB pass above, then mapping the sorted keys back.
  */
-pub fn radix_sort_int<T>(vals: &mut Vec<T>)
+pub fn radix_sort<T>(vals: &mut Vec<T>)
 where
-    T: Radix + Clone + Copy + Debug,
+    T: RadixKey,
 {
-    let num_iters = size_of::<T>();
-    let mut counts: [usize; NUM_BUCKETS] = [0; NUM_BUCKETS];
-    let mut offsets: [usize; NUM_BUCKETS] = [0; NUM_BUCKETS];
-    let mut vals_buf: Vec<T> = vals.clone();
-
-    for n_iter in 0..num_iters {
-        let shift_bits = (n_iter * SHIFT_BITS) as u8;
-
-        counts.iter_mut().for_each(|c| *c = 0);
-        vals.iter()
-            .map(|n| n.to_radix(shift_bits))
-            .for_each(|b| counts[b] += 1);
-
-        // Negative values only need to be handled on the final iteration. This is because the sign
-        // bit is always MSB.
-        if n_iter == num_iters - 1 {
-            // Negative values are contained in the latter 128 bins. Recall that 127 = 0x7f = 0111 1111.
-            offsets[0] = counts[FIRST_NEG_BUCKET..].iter().sum();
-            (1..FIRST_NEG_BUCKET).for_each(|i| offsets[i] = offsets[i - 1] + counts[i - 1]);
-
-            // Reverse order prefix sum to fix the ordering for negative values.
-            offsets[FIRST_NEG_BUCKET] = 0;
-            (FIRST_NEG_BUCKET+1 .. NUM_BUCKETS).for_each(|i| offsets[i] = offsets[i - 1] + counts[i - 1]);
-
-        } else {
-            offsets[0] = 0;
-            (1..NUM_BUCKETS).for_each(|i| offsets[i] = offsets[i - 1] + counts[i - 1]);
-        }
-
-        for n in &vals_buf {
-            let b = n.to_radix(shift_bits);
-            unsafe {
-                *vals.get_unchecked_mut(*offsets.get_unchecked(b)) = *n;
-            }
-            offsets[b] += 1;
-        }
-        vals_buf.copy_from_slice(&vals);
+    let mut keys: Vec<T::Key> = vals.iter().map(|v| v.to_key()).collect();
+    radix_sort_keys(&mut keys);
+    for (v, k) in vals.iter_mut().zip(keys) {
+        *v = T::from_key(k);
     }
 }
 
@@ -215,7 +320,7 @@ where
  */
 #[cfg(test)]
 mod tests {
-    use crate::{RadixSortFloat, RadixSortInt};
+    use crate::RadixSort;
 
     #[test]
     fn test_sort_i32() {
@@ -316,4 +421,59 @@ mod tests {
         vals.radix_sort();
         assert_eq!(expected, vals);
     }
+
+    #[test]
+    fn test_sort_f64_negative_zero() {
+        let mut vals: Vec<f64> = vec![1.0, -0.0, 0.0, -1.0];
+        vals.radix_sort();
+        assert_eq!(vals, vec![-1.0, -0.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_sort_u8() {
+        let mut vals: Vec<u8> = (0..=255).rev().collect();
+        let expected: Vec<u8> = (0..=255).collect();
+        vals.radix_sort();
+        assert_eq!(expected, vals);
+    }
+
+    #[test]
+    fn test_sort_i16() {
+        let mut vals: Vec<i16> = (-512..512).rev().collect();
+        let expected: Vec<i16> = (-512..512).collect();
+        vals.radix_sort();
+        assert_eq!(expected, vals);
+    }
+
+    #[test]
+    fn test_sort_bool() {
+        let mut vals: Vec<bool> = vec![true, false, true, false, false];
+        let expected: Vec<bool> = vec![false, false, false, true, true];
+        vals.radix_sort();
+        assert_eq!(expected, vals);
+    }
+
+    #[test]
+    fn test_sort_char() {
+        let mut vals: Vec<char> = "dcba".chars().collect();
+        let expected: Vec<char> = "abcd".chars().collect();
+        vals.radix_sort();
+        assert_eq!(expected, vals);
+    }
+
+    #[test]
+    fn test_sort_usize() {
+        let mut vals: Vec<usize> = (0..1024).rev().collect();
+        let expected: Vec<usize> = (0..1024).collect();
+        vals.radix_sort();
+        assert_eq!(expected, vals);
+    }
+
+    #[test]
+    fn test_sort_isize() {
+        let mut vals: Vec<isize> = (-512..512).rev().collect();
+        let expected: Vec<isize> = (-512..512).collect();
+        vals.radix_sort();
+        assert_eq!(expected, vals);
+    }
 }
@@ -0,0 +1,294 @@
+use num_traits::Zero;
+use std::{fmt::Debug, mem::size_of};
+
+use crate::radix::msb::{insertion_sort, INSERTION_SORT_THRESHOLD};
+use crate::radix::radix::{Radix, RadixKey, NUM_BUCKETS, SHIFT_BITS};
+
+/**
+ * ====================================================================================================
+ * Software write-combining (SWC) scatter. Scattering each element directly to `vals[offsets[b]]`
+ * touches 256 effectively random cache lines per pass on large inputs. Instead, each bucket gets a
+ * small staging buffer (`SWC_BUF_SIZE` elements); elements are appended to their bucket's staging
+ * buffer and only flushed out to `vals` once the buffer fills, which turns 256 scattered streams into
+ * mostly-sequential bursts of `SWC_BUF_SIZE`-sized writes.
+ * ====================================================================================================
+ */
+const SWC_BUF_SIZE: usize = 8;
+
+struct StagingBuffers<T> {
+    bufs: Vec<T>,
+    fill: [usize; NUM_BUCKETS],
+}
+
+impl<T> StagingBuffers<T>
+where
+    T: Copy + Zero,
+{
+    fn new() -> Self {
+        StagingBuffers {
+            bufs: vec![T::zero(); NUM_BUCKETS * SWC_BUF_SIZE],
+            fill: [0; NUM_BUCKETS],
+        }
+    }
+
+    /// Stage `n` into bucket `b`. When the bucket's staging buffer fills, its contents are flushed to
+    /// `vals` starting at `offsets[b]`, and `offsets[b]` is advanced past the flushed run.
+    #[inline]
+    fn push(&mut self, b: usize, n: T, vals: &mut [T], offsets: &mut [usize; NUM_BUCKETS]) {
+        let base = b * SWC_BUF_SIZE;
+        self.bufs[base + self.fill[b]] = n;
+        self.fill[b] += 1;
+        if self.fill[b] == SWC_BUF_SIZE {
+            let start = offsets[b];
+            vals[start..start + SWC_BUF_SIZE].copy_from_slice(&self.bufs[base..base + SWC_BUF_SIZE]);
+            offsets[b] += SWC_BUF_SIZE;
+            self.fill[b] = 0;
+        }
+    }
+
+    fn flush_remaining(&mut self, vals: &mut [T], offsets: &mut [usize; NUM_BUCKETS]) {
+        for b in 0..NUM_BUCKETS {
+            let n = self.fill[b];
+            if n == 0 {
+                continue;
+            }
+            let base = b * SWC_BUF_SIZE;
+            let start = offsets[b];
+            vals[start..start + n].copy_from_slice(&self.bufs[base..base + n]);
+            offsets[b] += n;
+            self.fill[b] = 0;
+        }
+    }
+
+    /// Clears `fill` so the staging buffers can be reused for another pass. `flush_remaining`
+    /// already drains every bucket back to zero, so this only needs to guard against a pass that
+    /// never ran; it's a 256-`usize` write, not a reallocation, so recursive callers can call it
+    /// once per level instead of building a fresh `StagingBuffers`.
+    fn reset(&mut self) {
+        self.fill = [0; NUM_BUCKETS];
+    }
+}
+
+/**
+ * SWC variant of the LSB core in `radix.rs`: identical bucketing and offset bookkeeping, but elements
+ * are staged per-bucket before being flushed to `vals` instead of being scattered one at a time.
+ */
+fn radix_sort_swc_keys<K>(vals: &mut Vec<K>)
+where
+    K: Radix + Clone + Copy + Debug + Zero,
+{
+    let num_iters = size_of::<K>();
+    let mut counts: [usize; NUM_BUCKETS] = [0; NUM_BUCKETS];
+    let mut offsets: [usize; NUM_BUCKETS] = [0; NUM_BUCKETS];
+    let mut vals_buf: Vec<K> = vals.clone();
+    let mut staging: StagingBuffers<K> = StagingBuffers::new();
+
+    for n_iter in 0..num_iters {
+        let shift_bits = (n_iter * SHIFT_BITS) as u8;
+
+        counts.iter_mut().for_each(|c| *c = 0);
+        vals.iter()
+            .map(|n| n.to_radix(shift_bits))
+            .for_each(|b| counts[b] += 1);
+
+        offsets[0] = 0;
+        (1..NUM_BUCKETS).for_each(|i| offsets[i] = offsets[i - 1] + counts[i - 1]);
+
+        for n in &vals_buf {
+            let b = n.to_radix(shift_bits);
+            staging.push(b, *n, vals, &mut offsets);
+        }
+        staging.flush_remaining(vals, &mut offsets);
+
+        vals_buf.copy_from_slice(vals);
+    }
+}
+
+/**
+ * Sorts `vals` in place with the SWC-staged LSB sort: maps every element through `RadixKey` into its
+ * unsigned key, sorts the keys, then maps them back.
+ */
+pub fn radix_sort_swc<T>(vals: &mut Vec<T>)
+where
+    T: RadixKey,
+    T::Key: Zero,
+{
+    let mut keys: Vec<T::Key> = vals.iter().map(|v| v.to_key()).collect();
+    radix_sort_swc_keys(&mut keys);
+    for (v, k) in vals.iter_mut().zip(keys) {
+        *v = T::from_key(k);
+    }
+}
+
+/// SWC-staged counterpart to `msb`'s recursive bucketing, used by the MSB+SWC combination below.
+/// `staging` is allocated once by `msb_swc_sort_keys` and threaded down through every recursive
+/// call rather than rebuilt per bucket, since a fresh `StagingBuffers` reallocates and zeroes its
+/// `256 * SWC_BUF_SIZE`-element backing `Vec` on every call, which would otherwise happen once per
+/// bucket at every recursion depth and defeat the point of staging writes in the first place.
+fn msb_recurse_swc<K>(vals: &mut [K], buf: &mut [K], shift_bits: u8, staging: &mut StagingBuffers<K>)
+where
+    K: Radix + Clone + Copy + Debug + PartialOrd + Zero,
+{
+    if vals.len() <= INSERTION_SORT_THRESHOLD {
+        insertion_sort(vals);
+        return;
+    }
+
+    let mut counts: [usize; NUM_BUCKETS] = [0; NUM_BUCKETS];
+    vals.iter()
+        .map(|n| n.to_radix(shift_bits))
+        .for_each(|b| counts[b] += 1);
+
+    let mut offsets: [usize; NUM_BUCKETS] = [0; NUM_BUCKETS];
+    offsets[0] = 0;
+    (1..NUM_BUCKETS).for_each(|i| offsets[i] = offsets[i - 1] + counts[i - 1]);
+    let bucket_starts = offsets;
+
+    buf.copy_from_slice(vals);
+    staging.reset();
+    for n in buf.iter() {
+        let b = n.to_radix(shift_bits);
+        staging.push(b, *n, vals, &mut offsets);
+    }
+    staging.flush_remaining(vals, &mut offsets);
+
+    if shift_bits == 0 {
+        return;
+    }
+
+    for b in 0..NUM_BUCKETS {
+        let (start, end) = (bucket_starts[b], offsets[b]);
+        if end > start {
+            msb_recurse_swc(
+                &mut vals[start..end],
+                &mut buf[start..end],
+                shift_bits - SHIFT_BITS as u8,
+                staging,
+            );
+        }
+    }
+}
+
+fn msb_swc_sort_keys<K>(keys: &mut Vec<K>)
+where
+    K: Radix + Clone + Copy + Debug + PartialOrd + Zero,
+{
+    let num_iters = size_of::<K>();
+    if num_iters == 0 {
+        return;
+    }
+    let top_shift = ((num_iters - 1) * SHIFT_BITS) as u8;
+    let mut buf: Vec<K> = keys.clone();
+    let mut staging: StagingBuffers<K> = StagingBuffers::new();
+    msb_recurse_swc(keys, &mut buf, top_shift, &mut staging);
+}
+
+/**
+ * Sorts `vals` in place using the MSB+SWC combination: MSB's recursive short-circuiting on buckets
+ * at or below `INSERTION_SORT_THRESHOLD`, scattered through the SWC staging buffers above.
+ */
+pub fn radix_sort_msb_swc<T>(vals: &mut Vec<T>)
+where
+    T: RadixKey,
+    T::Key: PartialOrd + Zero,
+{
+    let mut keys: Vec<T::Key> = vals.iter().map(|v| v.to_key()).collect();
+    msb_swc_sort_keys(&mut keys);
+    for (v, k) in vals.iter_mut().zip(keys) {
+        *v = T::from_key(k);
+    }
+}
+
+/**
+ * ====================================================================================================
+ * Tests. As in `msb.rs`, `radix_sort` (LSB) is the oracle; these cross-check the SWC scatter and the
+ * MSB+SWC combination against it across lengths straddling `INSERTION_SORT_THRESHOLD`, all-equal
+ * buckets, and negative floats.
+ * ====================================================================================================
+ */
+#[cfg(test)]
+mod tests {
+    use crate::radix::msb::INSERTION_SORT_THRESHOLD;
+    use crate::RadixSort;
+
+    const TEST_LENS: [usize; 9] = [0, 1, 31, 32, 33, 63, 256, 1000, 5000];
+
+    #[test]
+    fn test_swc_matches_lsb_i32() {
+        for &n in &TEST_LENS {
+            let mut vals: Vec<i32> = (0..n as i32).rev().map(|i| i - (n as i32) / 2).collect();
+            let mut expected = vals.clone();
+            expected.radix_sort();
+            vals.radix_sort_swc();
+            assert_eq!(vals, expected, "n = {}", n);
+        }
+    }
+
+    #[test]
+    fn test_swc_matches_lsb_f64_with_negatives() {
+        for &n in &TEST_LENS {
+            let mut vals: Vec<f64> = (0..n as i64)
+                .rev()
+                .map(|i| (i - (n as i64) / 2) as f64 * 0.5)
+                .collect();
+            let mut expected = vals.clone();
+            expected.radix_sort();
+            vals.radix_sort_swc();
+            assert_eq!(vals, expected, "n = {}", n);
+        }
+    }
+
+    #[test]
+    fn test_msb_swc_matches_lsb_i32() {
+        for &n in &TEST_LENS {
+            let mut vals: Vec<i32> = (0..n as i32).rev().map(|i| i - (n as i32) / 2).collect();
+            let mut expected = vals.clone();
+            expected.radix_sort();
+            vals.radix_sort_msb_swc();
+            assert_eq!(vals, expected, "n = {}", n);
+        }
+    }
+
+    #[test]
+    fn test_msb_swc_matches_lsb_f64_with_negatives() {
+        for &n in &TEST_LENS {
+            let mut vals: Vec<f64> = (0..n as i64)
+                .rev()
+                .map(|i| (i - (n as i64) / 2) as f64 * 0.5)
+                .collect();
+            let mut expected = vals.clone();
+            expected.radix_sort();
+            vals.radix_sort_msb_swc();
+            assert_eq!(vals, expected, "n = {}", n);
+        }
+    }
+
+    #[test]
+    fn test_swc_all_equal_buckets() {
+        for &n in &TEST_LENS {
+            let mut vals: Vec<i32> = vec![7; n];
+            let expected = vals.clone();
+            vals.radix_sort_swc();
+            assert_eq!(vals, expected, "n = {}", n);
+
+            let mut vals: Vec<i32> = vec![7; n];
+            vals.radix_sort_msb_swc();
+            assert_eq!(vals, expected, "n = {}", n);
+        }
+    }
+
+    #[test]
+    fn test_msb_swc_insertion_sort_threshold_boundary() {
+        for &n in &[
+            INSERTION_SORT_THRESHOLD - 1,
+            INSERTION_SORT_THRESHOLD,
+            INSERTION_SORT_THRESHOLD + 1,
+        ] {
+            let mut vals: Vec<i32> = (0..n as i32).rev().collect();
+            let mut expected = vals.clone();
+            expected.radix_sort();
+            vals.radix_sort_msb_swc();
+            assert_eq!(vals, expected, "n = {}", n);
+        }
+    }
+}